@@ -28,6 +28,35 @@ impl<'list, Node: LinkedList<'list>> Link<'list, Node> {
     }
 }
 
+/**
+ * Size counter for the header of a linked list. Nodes keep it up to date by
+ * forwarding their grow() and shrink() hooks to it, so that the length of the
+ * list can be read in O(1) instead of walking the ring with len().
+ */
+struct Counter {
+    size: Cell<usize>,
+}
+
+impl Counter {
+    fn new() -> Self {
+        Self {
+            size: Cell::from(0),
+        }
+    }
+
+    fn get(&self) -> usize {
+        self.size.get()
+    }
+
+    fn grow(&self) {
+        self.size.set(self.size.get() + 1);
+    }
+
+    fn shrink(&self) {
+        self.size.set(self.size.get() - 1);
+    }
+}
+
 /**
  * An intrusive linked list. Used to allow reversible removal of items from a
  * list of active objects.
@@ -81,6 +110,11 @@ trait LinkedList<'list>: std::marker::Sized {
 
     /**
      * Reinserts a node into its parent linked list.
+     * Removed nodes must be reinserted in exactly the reverse order of their
+     * removal: a removed node still points at the neighbours it had at the
+     * time, which are only its neighbours again once every node removed after
+     * it has been reinserted. Any other order is unsupported and may leave the
+     * list in an inconsistent state.
      */
     fn reinsert(&'list self) {
         self.grow();
@@ -94,7 +128,7 @@ trait LinkedList<'list>: std::marker::Sized {
      * TODO: See if unwrap() impacts performance, consider unwrap_unchecked().
      */
     fn next(&'list self) -> &'list Self {
-        self.link().next.get().unwrap()
+        self.try_next().unwrap()
     }
 
     /**
@@ -103,7 +137,23 @@ trait LinkedList<'list>: std::marker::Sized {
      * TODO: See if unwrap() impacts performance, consider unwrap_unchecked().
      */
     fn previous(&'list self) -> &'list Self {
-        self.link().previous.get().unwrap()
+        self.try_previous().unwrap()
+    }
+
+    /**
+     * Non-panicking version of next(): returns None if the link has not been
+     * initialized yet.
+     */
+    fn try_next(&'list self) -> Option<&'list Self> {
+        self.link().next.get()
+    }
+
+    /**
+     * Non-panicking version of previous(): returns None if the link has not
+     * been initialized yet.
+     */
+    fn try_previous(&'list self) -> Option<&'list Self> {
+        self.link().previous.get()
     }
 
     fn set_next(&'list self, node: &'list Self) {
@@ -118,15 +168,33 @@ trait LinkedList<'list>: std::marker::Sized {
      * A linked list is empty if it is connected to itself.
      */
     fn is_empty(&'list self) -> bool {
-        self.next() as *const Self == self as *const Self
-        && self.previous() as *const Self == self as *const Self
+        std::ptr::eq(self.next(), self) && std::ptr::eq(self.previous(), self)
+    }
+
+    /**
+     * Number of nodes in the list, not counting this one. Walks the whole
+     * ring, so takes O(n); lists that need their length in O(1) should keep
+     * count through the grow() and shrink() hooks instead.
+     * Caller must ensure that this node is currently linked into a consistent
+     * ring, e.g. the list header. The walk only stops once it returns to this
+     * node, so calling it on a removed node or on a corrupted ring may never
+     * terminate.
+     */
+    fn len(&'list self) -> usize {
+        let mut length = 0;
+        let mut node = self.next();
+        while !std::ptr::eq(node, self) {
+            length += 1;
+            node = node.next();
+        }
+        length
     }
 
     /**
      * A linked list node is valid if both its neighbour pointers exist.
      */
     fn is_valid(&'list self) -> bool {
-        self.link().next.get().is_some() && self.link().previous.get().is_some()
+        self.try_next().is_some() && self.try_previous().is_some()
     }
 }
 
@@ -155,6 +223,27 @@ mod test {
         assert!(list.is_empty());
     }
 
+    #[test]
+    fn fallible_access() {
+        let list = List { link: Link::uninitialized() };
+        assert!(list.try_next().is_none());
+        assert!(list.try_previous().is_none());
+
+        list.connect_self();
+
+        assert_eq!(list.try_next().map(|node| node as *const List), Some(&list as *const List));
+        assert_eq!(list.try_previous().map(|node| node as *const List), Some(&list as *const List));
+
+        let node = List { link: Link::uninitialized() };
+        assert!(node.try_next().is_none());
+        assert!(node.try_previous().is_none());
+
+        list.prepend(&node);
+
+        assert_eq!(node.try_next().map(|node| node as *const List), Some(&list as *const List));
+        assert_eq!(node.try_previous().map(|node| node as *const List), Some(&list as *const List));
+    }
+
     #[test]
     fn insertion() {
         let list = List { link: Link::uninitialized() };
@@ -249,9 +338,101 @@ mod test {
         assert_eq!(nodes[2].previous() as *const List, &nodes[1] as *const List);
     }
 
+    #[test]
+    fn reverse_order_restoration() {
+        let nodes: [List; 4] = [
+            List{ link: Link::uninitialized() },
+            List{ link: Link::uninitialized() },
+            List{ link: Link::uninitialized() },
+            List{ link: Link::uninitialized() }
+        ];
+
+        nodes[0].connect_self();
+        nodes[0].prepend(&nodes[1]);
+        nodes[0].prepend(&nodes[2]);
+        nodes[0].prepend(&nodes[3]);
+
+        let neighbours = || -> Vec<(*const List, *const List)> {
+            nodes.iter()
+                .map(|node| (node.next() as *const List, node.previous() as *const List))
+                .collect()
+        };
+        let original = neighbours();
+
+        nodes[2].remove();
+        nodes[1].remove();
+        nodes[3].remove();
+        assert!(nodes[0].is_empty());
+
+        nodes[3].reinsert();
+        nodes[1].reinsert();
+        nodes[2].reinsert();
+        assert_eq!(neighbours(), original);
+    }
+
+    #[test]
+    fn out_of_order_reinsertion() {
+        let nodes: [List; 4] = [
+            List{ link: Link::uninitialized() },
+            List{ link: Link::uninitialized() },
+            List{ link: Link::uninitialized() },
+            List{ link: Link::uninitialized() }
+        ];
+
+        nodes[0].connect_self();
+        nodes[0].prepend(&nodes[1]);
+        nodes[0].prepend(&nodes[2]);
+        nodes[0].prepend(&nodes[3]);
+
+        nodes[1].remove();
+        nodes[2].remove();
+        nodes[1].reinsert();
+
+        // Walks are bounded, since an inconsistent ring need not lead back to
+        // the start.
+        let mut forward = 0;
+        let mut node = nodes[0].next();
+        while !std::ptr::eq(node, &nodes[0]) && forward <= nodes.len() {
+            forward += 1;
+            node = node.next();
+        }
+
+        let mut backward = 0;
+        let mut node = nodes[0].previous();
+        while !std::ptr::eq(node, &nodes[0]) && backward <= nodes.len() {
+            backward += 1;
+            node = node.previous();
+        }
+
+        assert_ne!(forward, backward);
+    }
+
+    #[test]
+    fn length_from_member() {
+        let nodes: [List; 4] = [
+            List{ link: Link::uninitialized() },
+            List{ link: Link::uninitialized() },
+            List{ link: Link::uninitialized() },
+            List{ link: Link::uninitialized() }
+        ];
+
+        nodes[0].connect_self();
+        nodes[0].prepend(&nodes[1]);
+        nodes[0].prepend(&nodes[2]);
+        nodes[0].prepend(&nodes[3]);
+
+        assert_eq!(nodes[0].len(), 3);
+        assert_eq!(nodes[2].len(), 3);
+
+        nodes[1].remove();
+        assert_eq!(nodes[0].len(), 2);
+        assert_eq!(nodes[2].len(), 2);
+        assert_eq!(nodes[3].len(), 2);
+    }
+
     struct Header<'list> {
         list: SizedList<'list>,
-        size: Cell<usize>,
+        size: Counter,
     }
 
     impl<'list> Header<'list> {
@@ -271,13 +452,11 @@ mod test {
         }
 
         fn grow(&self) {
-            let increased_size = self.parent.get().unwrap().size.get() + 1;
-            self.parent.get().unwrap().size.set(increased_size);
+            self.parent.get().unwrap().size.grow();
         }
 
         fn shrink(&self) {
-            let decreased_size = self.parent.get().unwrap().size.get() - 1;
-            self.parent.get().unwrap().size.set(decreased_size);
+            self.parent.get().unwrap().size.shrink();
         }
     }
 
@@ -288,7 +467,7 @@ mod test {
                 link: Link::uninitialized(),
                 parent: Cell::from(None),
             },
-            size: Cell::from(0),
+            size: Counter::new(),
         };
 
         let nodes: [SizedList; 2] = [
@@ -315,4 +494,47 @@ mod test {
         nodes[1].reinsert();
         assert_eq!(header.size(), 2);
     }
+
+    #[test]
+    fn length() {
+        let header = Header {
+            list: SizedList {
+                link: Link::uninitialized(),
+                parent: Cell::from(None),
+            },
+            size: Counter::new(),
+        };
+
+        let nodes: [SizedList; 3] = [
+            SizedList{ link: Link::uninitialized(), parent: Cell::from(Some(&header)) },
+            SizedList{ link: Link::uninitialized(), parent: Cell::from(Some(&header)) },
+            SizedList{ link: Link::uninitialized(), parent: Cell::from(Some(&header)) }
+        ];
+
+        header.list.connect_self();
+        header.list.parent.set(Some(&header));
+        assert_eq!(header.list.len(), 0);
+
+        for node in nodes.iter() {
+            header.list.prepend(node);
+        }
+        assert_eq!(header.size(), 3);
+        assert_eq!(header.list.len(), header.size());
+
+        nodes[1].remove();
+        assert_eq!(header.list.len(), header.size());
+
+        nodes[0].remove();
+        nodes[2].remove();
+        assert_eq!(header.size(), 0);
+        assert_eq!(header.list.len(), header.size());
+
+        nodes[2].reinsert();
+        nodes[0].reinsert();
+        assert_eq!(header.list.len(), header.size());
+
+        nodes[1].reinsert();
+        assert_eq!(header.size(), 3);
+        assert_eq!(header.list.len(), header.size());
+    }
 }
\ No newline at end of file